@@ -1,11 +1,16 @@
 #![no_std]
 use core::{
     cmp::{Eq, Ord, Ordering, PartialEq},
+    fmt,
     mem::size_of,
-    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
+    ops::{
+        Add, AddAssign, BitAnd, BitOr, BitXor, Div, DivAssign, Mul, MulAssign, Neg, Not, Rem,
+        RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+    },
+    str::FromStr,
 };
 
-use bytemuck::{Pod, Zeroable};
+use bytemuck::Zeroable;
 
 pub const NUMBER_SIZE: usize = 10 * u8::MAX as usize;
 pub const BASE: u32 = u16::MAX as u32;
@@ -19,11 +24,191 @@ type SignedUpperBase = i64;
 #[derive(Copy, Clone, Debug)]
 pub struct BigNumber {
     pub prec: [BaseType; NUMBER_SIZE],
+    /// Index one past the highest non-zero limb (`0` for the number zero).
+    /// Kept up to date by every operation below so they cost time
+    /// proportional to the operands' actual size, not the fixed
+    /// `NUMBER_SIZE` storage. Call [`Self::recompute_len`] after writing
+    /// `prec` directly.
+    len: usize,
 }
 
-unsafe impl Pod for BigNumber {}
+// Not `Pod`: a `len` outside `0..=NUMBER_SIZE`, or one that disagrees with
+// `prec`, is reachable from an arbitrary byte pattern and would make
+// `len`-trusting code (e.g. `leading_zeros`) index out of bounds. `Zeroable`
+// stays valid since the all-zero pattern is exactly `Self::empty()`.
 unsafe impl Zeroable for BigNumber {}
 
+/// The sign of a [`SignedBigNumber`]. Zero is always [`Sign::Positive`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Sign {
+    Positive,
+    Negative,
+}
+
+/// A [`BigNumber`] magnitude paired with a [`Sign`]. `BigNumber` itself stays
+/// unsigned so its `Sub` keeps clamping at zero for callers that only ever
+/// work with magnitudes; reach for `SignedBigNumber` when a computation can
+/// go negative.
+#[derive(Copy, Clone, Debug)]
+pub struct SignedBigNumber {
+    pub sign: Sign,
+    pub magnitude: BigNumber,
+}
+
+/// Multiplies the limbs of `buf` in place by the small integer `d`,
+/// returning the carry limb produced past the end of `buf`.
+fn mul_small(buf: &mut [BaseType], d: u64) -> BaseType {
+    let mut carry: u64 = 0;
+    for limb in buf.iter_mut() {
+        let p = *limb as u64 * d + carry;
+        *limb = (p % BASE as u64) as BaseType;
+        carry = p / BASE as u64;
+    }
+    carry as BaseType
+}
+
+/// Writes `value` (which must fit in 32 digits of base `radix`, and always
+/// does for the chunk sizes [`BigNumber::write_radix`] uses) as digits in
+/// base `radix`, zero-padded to `width` digits (`0` for no padding, used on
+/// the most significant chunk).
+fn write_radix_chunk(
+    f: &mut fmt::Formatter<'_>,
+    mut value: u64,
+    radix: u64,
+    width: usize,
+) -> fmt::Result {
+    let mut buf = [0u8; 32];
+    let mut i = buf.len();
+    loop {
+        i -= 1;
+        buf[i] = char::from_digit((value % radix) as u32, radix as u32).unwrap() as u8;
+        value /= radix;
+        if value == 0 {
+            break;
+        }
+    }
+
+    for _ in (buf.len() - i)..width {
+        f.write_str("0")?;
+    }
+    f.write_str(core::str::from_utf8(&buf[i..]).unwrap())
+}
+
+/// Significant-limb count above which `MulAssign` switches from schoolbook
+/// to the recursive Karatsuba multiplication.
+const KARATSUBA_THRESHOLD: usize = 32;
+
+/// Computes `2^bits` as a `BigNumber` via square-and-multiply, truncating
+/// like every other op in this crate if the result overflows `NUMBER_SIZE`.
+fn pow2(bits: usize) -> BigNumber {
+    let mut result = BigNumber::from(1);
+    let mut base = BigNumber::from(2);
+    let mut e = bits;
+
+    while e > 0 {
+        if e & 1 == 1 {
+            result *= base;
+        }
+        base *= base;
+        e >>= 1;
+    }
+
+    result
+}
+
+fn to_bignum(limbs: &[BaseType]) -> BigNumber {
+    let mut out = BigNumber::empty();
+    out.prec[..limbs.len()].copy_from_slice(limbs);
+    out.normalize(limbs.len());
+    out
+}
+
+/// Returns `num` shifted up by `shift` limbs (i.e. multiplied by
+/// `BASE^shift`), truncating limbs that fall off the top like every other
+/// op in this crate.
+fn shift_limbs(num: &BigNumber, shift: usize) -> BigNumber {
+    let mut out = BigNumber::empty();
+    if shift >= NUMBER_SIZE {
+        return out;
+    }
+    let n = num.len.min(NUMBER_SIZE - shift);
+    out.prec[shift..shift + n].copy_from_slice(&num.prec[..n]);
+    out.normalize(shift + n);
+    out
+}
+
+/// Plain O(xy) convolution of two limb slices, truncated to `NUMBER_SIZE`.
+fn schoolbook_mul(x: &[BaseType], y: &[BaseType]) -> BigNumber {
+    let mut out = BigNumber::empty();
+
+    for (i, &xi) in x.iter().enumerate() {
+        if xi == 0 {
+            continue;
+        }
+
+        let mut carry: u64 = 0;
+        for (j, &yj) in y.iter().enumerate() {
+            let pos = i + j;
+            if pos >= NUMBER_SIZE {
+                break;
+            }
+
+            let acc = out.prec[pos] as u64 + xi as u64 * yj as u64 + carry;
+            out.prec[pos] = (acc % BASE as u64) as BaseType;
+            carry = acc / BASE as u64;
+        }
+
+        let mut pos = i + y.len();
+        while carry != 0 && pos < NUMBER_SIZE {
+            let acc = out.prec[pos] as u64 + carry;
+            out.prec[pos] = (acc % BASE as u64) as BaseType;
+            carry = acc / BASE as u64;
+            pos += 1;
+        }
+    }
+
+    out.normalize((x.len() + y.len()).min(NUMBER_SIZE));
+    out
+}
+
+/// Recursive Karatsuba multiplication: splits `x` and `y` at half their
+/// significant length `k` into `x = x_hi * BASE^k + x_lo` (and likewise for
+/// `y`), then combines `z0 = x_lo*y_lo`, `z2 = x_hi*y_hi` and
+/// `z1 = (x_lo+x_hi)*(y_lo+y_hi) - z2 - z0` into
+/// `z2*BASE^2k + z1*BASE^k + z0`. Falls back to schoolbook at or below
+/// `KARATSUBA_THRESHOLD` significant limbs.
+fn karatsuba_mul(x: &[BaseType], y: &[BaseType]) -> BigNumber {
+    if x.is_empty() || y.is_empty() {
+        return BigNumber::empty();
+    }
+
+    if x.len().max(y.len()) <= KARATSUBA_THRESHOLD {
+        return schoolbook_mul(x, y);
+    }
+
+    let k = x.len().max(y.len()).div_ceil(2);
+
+    let x_lo = &x[..k.min(x.len())];
+    let x_hi = if x.len() > k { &x[k..] } else { &[][..] };
+    let y_lo = &y[..k.min(y.len())];
+    let y_hi = if y.len() > k { &y[k..] } else { &[][..] };
+
+    let z0 = karatsuba_mul(x_lo, y_lo);
+    let z2 = karatsuba_mul(x_hi, y_hi);
+
+    let x_sum = to_bignum(x_lo) + to_bignum(x_hi);
+    let y_sum = to_bignum(y_lo) + to_bignum(y_hi);
+
+    let mut z1 = karatsuba_mul(&x_sum.prec[..x_sum.len], &y_sum.prec[..y_sum.len]);
+    z1 -= z2;
+    z1 -= z0;
+
+    let mut result = z0;
+    result += shift_limbs(&z1, k);
+    result += shift_limbs(&z2, 2 * k);
+    result
+}
+
 fn collect_array<T, I, const N: usize>(itr: I) -> [T; N]
 where
     T: Default + Copy,
@@ -51,12 +236,14 @@ impl BigNumber {
     pub fn empty() -> Self {
         Self {
             prec: [0; NUMBER_SIZE],
+            len: 0,
         }
     }
 
     pub fn from(num: BaseType) -> Self {
         let mut s = Self::new();
         s.prec[0] = num;
+        s.len = if num != 0 { 1 } else { 0 };
         s
     }
 
@@ -65,11 +252,12 @@ impl BigNumber {
 
         s.prec[0] = num.rem_euclid(BASE) as BaseType;
         s.prec[1] = (num / BASE) as BaseType;
+        s.normalize(2);
         s
     }
 
     pub fn is_zero(&self) -> bool {
-        self.prec.iter().all(|&x| x == 0)
+        self.len == 0
     }
 
     pub fn from_ne_bytes(bytes: &[u8; NUMBER_SIZE * BASE_SIZE]) -> Self {
@@ -77,38 +265,384 @@ impl BigNumber {
             .chunks(BASE_SIZE)
             .map(|b| BaseType::from_ne_bytes(b.try_into().unwrap()));
 
-        Self {
+        let mut s = Self {
             prec: collect_array::<BaseType, _, NUMBER_SIZE>(prec),
-        }
+            len: 0,
+        };
+        s.normalize(NUMBER_SIZE);
+        s
     }
 
     pub fn leading_zeros(&self) -> usize {
-        for &chunk in self.prec.iter().rev() {
-            if chunk != 0 {
-                return chunk.leading_zeros() as usize;
+        if self.len == 0 {
+            return NUMBER_SIZE * 32; // If the number is zero, return the size of the number
+        }
+        self.prec[self.len - 1].leading_zeros() as usize
+    }
+
+    /// Recomputes `len` by trimming down from `upper_bound` (capped to
+    /// `NUMBER_SIZE`) instead of rescanning all of `prec`, so normalizing
+    /// after an op costs no more than the op's own operand size.
+    fn normalize(&mut self, upper_bound: usize) {
+        let mut n = upper_bound.min(NUMBER_SIZE);
+        while n > 0 && self.prec[n - 1] == 0 {
+            n -= 1;
+        }
+        self.len = n;
+    }
+
+    /// Resyncs `len` after `prec` has been written to directly, by
+    /// rescanning the whole array. Needed because `len` is private but
+    /// `prec` is public.
+    pub fn recompute_len(&mut self) {
+        self.normalize(NUMBER_SIZE);
+    }
+
+    /// Divides `self` by `rhs`, returning `(quotient, remainder)`. Uses
+    /// Knuth's Algorithm D for multi-limb divisors, and a direct single-limb
+    /// loop when `rhs` fits in one limb. Panics if `rhs` is zero.
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        let n = rhs.len;
+        assert!(n != 0, "division by zero");
+
+        if self.cmp(rhs) == Ordering::Less {
+            return (Self::empty(), *self);
+        }
+
+        let m_n = self.len;
+
+        if n == 1 {
+            let d = rhs.prec[0] as u64;
+            let mut quotient = Self::empty();
+            let mut rem: u64 = 0;
+
+            for i in (0..m_n).rev() {
+                let cur = rem * BASE as u64 + self.prec[i] as u64;
+                quotient.prec[i] = (cur / d) as BaseType;
+                rem = cur % d;
             }
+
+            quotient.normalize(m_n);
+            return (quotient, Self::from(rem as BaseType));
         }
-        NUMBER_SIZE * 32 // If the number is zero, return the size of the number
+
+        let m = m_n - n;
+
+        // Normalize so the divisor's top limb is >= BASE / 2: this bounds
+        // how far the estimated quotient digit `qhat` can overshoot.
+        let d = BASE as u64 / (rhs.prec[n - 1] as u64 + 1);
+
+        let mut v: [BaseType; NUMBER_SIZE] = [0; NUMBER_SIZE];
+        v[..n].copy_from_slice(&rhs.prec[..n]);
+        mul_small(&mut v[..n], d);
+
+        let mut u: [BaseType; NUMBER_SIZE + 1] = [0; NUMBER_SIZE + 1];
+        u[..m_n].copy_from_slice(&self.prec[..m_n]);
+        u[m_n] = mul_small(&mut u[..m_n], d);
+
+        let mut quotient = Self::empty();
+
+        for j in (0..=m).rev() {
+            let num = u[j + n] as u64 * BASE as u64 + u[j + n - 1] as u64;
+            let mut qhat = num / v[n - 1] as u64;
+            let mut rhat = num % v[n - 1] as u64;
+
+            while qhat >= BASE as u64
+                || qhat * v[n - 2] as u64 > rhat * BASE as u64 + u[j + n - 2] as u64
+            {
+                qhat -= 1;
+                rhat += v[n - 1] as u64;
+                if rhat >= BASE as u64 {
+                    break;
+                }
+            }
+
+            // Multiply-and-subtract qhat * v from the window u[j..=j + n].
+            let mut borrow: i64 = 0;
+            let mut carry: u64 = 0;
+            for i in 0..n {
+                let p = qhat * v[i] as u64 + carry;
+                carry = p / BASE as u64;
+
+                let sub = u[j + i] as i64 - (p % BASE as u64) as i64 - borrow;
+                if sub < 0 {
+                    u[j + i] = (sub + BASE as i64) as BaseType;
+                    borrow = 1;
+                } else {
+                    u[j + i] = sub as BaseType;
+                    borrow = 0;
+                }
+            }
+            let sub = u[j + n] as i64 - carry as i64 - borrow;
+            if sub < 0 {
+                u[j + n] = (sub + BASE as i64) as BaseType;
+                borrow = 1;
+            } else {
+                u[j + n] = sub as BaseType;
+                borrow = 0;
+            }
+
+            if borrow != 0 {
+                // qhat was one too large: add the divisor back once.
+                qhat -= 1;
+                let mut carry2: u64 = 0;
+                for i in 0..n {
+                    let s = u[j + i] as u64 + v[i] as u64 + carry2;
+                    u[j + i] = (s % BASE as u64) as BaseType;
+                    carry2 = s / BASE as u64;
+                }
+                u[j + n] = ((u[j + n] as u64 + carry2) % BASE as u64) as BaseType;
+            }
+
+            quotient.prec[j] = qhat as BaseType;
+        }
+        quotient.normalize(m + 1);
+
+        // Denormalize the remainder by dividing the scaled limbs back by d.
+        let mut remainder = Self::empty();
+        let mut rem: u64 = 0;
+        for i in (0..n).rev() {
+            let cur = rem * BASE as u64 + u[i] as u64;
+            remainder.prec[i] = (cur / d) as BaseType;
+            rem = cur % d;
+        }
+        remainder.normalize(n);
+
+        (quotient, remainder)
+    }
+
+    /// Shifts `self` left by `bits` (multiplies by `2^bits`), truncating
+    /// like every other op. Limbs are digits in base [`BASE`], not a power
+    /// of two, so this can't just move limbs around like a real shift.
+    fn shl_bits(&self, bits: usize) -> Self {
+        *self * pow2(bits)
+    }
+
+    /// Shifts `self` right by `bits` (divides by `2^bits`, rounding toward
+    /// zero). See [`Self::shl_bits`] for why this goes through `pow2`.
+    fn shr_bits(&self, bits: usize) -> Self {
+        let (quotient, _) = self.div_rem(&pow2(bits));
+        quotient
+    }
+
+    /// Number of bits moved per [`Self::div_rem`] call in
+    /// [`Self::to_bit_words`]/[`Self::from_bit_words`]. Each word is below
+    /// `2^16`; since [`BASE`] sits one below `2^16`, a remainder can still
+    /// spill into a second limb (`2^16 - 1 == BASE`), so both remainder
+    /// limbs are read back.
+    const BIT_CHUNK: usize = 16;
+
+    /// Splits `self` into [`Self::BIT_CHUNK`]-bit words, least-significant
+    /// first. Limbs are digits in base [`BASE`], which isn't a power of two,
+    /// so they don't line up with binary; this is the binary view the
+    /// bitwise operators below need, built the same way [`Self::write_radix`]
+    /// peels off decimal/hex digits a chunk at a time.
+    fn to_bit_words(self) -> [u32; NUMBER_SIZE] {
+        let chunk = pow2(Self::BIT_CHUNK);
+        let mut words = [0u32; NUMBER_SIZE];
+        let mut e = self;
+        for word in words.iter_mut() {
+            if e.is_zero() {
+                break;
+            }
+            let (quotient, remainder) = e.div_rem(&chunk);
+            *word = remainder.prec[0] + remainder.prec[1] * BASE;
+            e = quotient;
+        }
+        words
+    }
+
+    /// Inverse of [`Self::to_bit_words`]: rebuilds a `BigNumber` from
+    /// [`Self::BIT_CHUNK`]-bit words, least-significant first.
+    fn from_bit_words(words: &[u32; NUMBER_SIZE]) -> Self {
+        let chunk = pow2(Self::BIT_CHUNK);
+        let mut acc = Self::empty();
+        for &word in words.iter().rev() {
+            acc *= chunk;
+            acc += Self::from_upper(word);
+        }
+        acc
     }
 
-    pub fn rotated_right(&mut self, shift: usize) {
-        let shift = shift % NUMBER_SIZE;
-        if shift != 0 {
-            let mut temp = [0; NUMBER_SIZE];
+    /// Returns `(self + rhs) mod m`, assuming `self < m` and `rhs < m`.
+    ///
+    /// The sum can overflow `m` by at most one multiple of it, so a single
+    /// conditional subtraction reduces it rather than a full division.
+    pub fn add_mod(self, rhs: Self, m: Self) -> Self {
+        let sum = self + rhs;
+        if sum >= m {
+            sum - m
+        } else {
+            sum
+        }
+    }
+
+    /// Returns `(self - rhs) mod m`, assuming `self < m` and `rhs < m`.
+    ///
+    /// `BigNumber` is unsigned, so a `rhs > self` difference is recovered
+    /// by adding `m` back in rather than going negative.
+    pub fn sub_mod(self, rhs: Self, m: Self) -> Self {
+        if self >= rhs {
+            self - rhs
+        } else {
+            m - (rhs - self)
+        }
+    }
 
-            temp.copy_from_slice(&self.prec);
+    /// Returns `(self * rhs) mod m` by multiplying and reducing the product
+    /// with [`Self::div_rem`]. The product is held in a `BigNumber`, so it
+    /// is exact only while it fits in `NUMBER_SIZE` limbs, which holds for
+    /// `self, rhs < m` when `m` has at most `NUMBER_SIZE / 2` limbs; a larger
+    /// product is truncated before the reduction.
+    pub fn mul_mod(self, rhs: Self, m: Self) -> Self {
+        let (_, remainder) = (self * rhs).div_rem(&m);
+        remainder
+    }
+
+    /// Returns `(self^exp) mod m` using left-to-right square-and-multiply:
+    /// walk the bits of `exp` from most to least significant, squaring the
+    /// accumulator every step and multiplying in `self` when the bit is set.
+    /// Every intermediate is produced through `mul_mod`, so it stays reduced
+    /// below `m`; the products are exact under the same bound on `m` that
+    /// `mul_mod` has (at most `NUMBER_SIZE / 2` limbs).
+    pub fn pow_mod(self, exp: Self, m: Self) -> Self {
+        let (_, one) = Self::from(1).div_rem(&m);
+        let bit_len = exp.bit_len();
+        if bit_len == 0 {
+            return one;
+        }
+
+        let (_, base) = self.div_rem(&m);
+        let two = Self::from(2);
+        let mut bits = [false; NUMBER_SIZE * 16];
+        let mut e = exp;
+        for bit in bits.iter_mut().take(bit_len) {
+            let (quotient, remainder) = e.div_rem(&two);
+            *bit = !remainder.is_zero();
+            e = quotient;
+        }
 
-            for i in 0..NUMBER_SIZE {
-                let j = (i + shift) % NUMBER_SIZE;
-                self.prec[i] = temp[j];
+        let mut acc = one;
+        for &bit in bits[..bit_len].iter().rev() {
+            acc = acc.mul_mod(acc, m);
+            if bit {
+                acc = acc.mul_mod(base, m);
             }
         }
+
+        acc
+    }
+
+    /// Upper bound on the number of bits needed to represent `self`, used by
+    /// [`Self::pow_mod`] to find the starting bit of its square-and-multiply
+    /// loop. Each limb holds a value below [`BASE`], which is in turn below
+    /// `2^16`, so charging every limb below the top one 16 bits is a safe
+    /// (and, since `BASE` sits one below `2^16`, nearly tight) bound.
+    fn bit_len(&self) -> usize {
+        let n = self.len;
+        if n == 0 {
+            return 0;
+        }
+        (n - 1) * 16 + (32 - self.prec[n - 1].leading_zeros() as usize)
+    }
+
+    /// Parses `s` as a `BigNumber` written in base `radix` (2 to 36,
+    /// following [`char::to_digit`]), the school-arithmetic way: start at
+    /// zero and for each digit multiply the accumulator by `radix` and add
+    /// the digit's value. Errors if `radix` is outside 2 to 36, `s` is
+    /// empty, or `s` contains a digit not valid for `radix`.
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseBigNumberError> {
+        if !(2..=36).contains(&radix) || s.is_empty() {
+            return Err(ParseBigNumberError);
+        }
+
+        let radix_num = Self::from(radix);
+        let mut acc = Self::empty();
+        for c in s.chars() {
+            let digit = c.to_digit(radix).ok_or(ParseBigNumberError)?;
+            acc *= radix_num;
+            acc += Self::from(digit);
+        }
+
+        Ok(acc)
+    }
+
+    /// Writes `self` in base `radix` (2 to 36), batching
+    /// `chunk_digits` digits per [`Self::div_rem`] call by dividing out
+    /// `radix^chunk_digits` at a time instead of one digit at a time.
+    /// `radix^chunk_digits` must stay under `BASE * BASE` so every
+    /// remainder fits in the two limbs read back out below.
+    fn write_radix(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        radix: u64,
+        chunk_digits: u32,
+    ) -> fmt::Result {
+        if self.is_zero() {
+            return f.write_str("0");
+        }
+
+        let chunk = radix.pow(chunk_digits);
+        let divisor = Self::from_upper(chunk as UpperBase);
+
+        let mut chunks = [0u64; NUMBER_SIZE];
+        let mut count = 0;
+        let mut cur = *self;
+        while !cur.is_zero() {
+            let (quotient, remainder) = cur.div_rem(&divisor);
+            chunks[count] = remainder.prec[0] as u64 + remainder.prec[1] as u64 * BASE as u64;
+            count += 1;
+            cur = quotient;
+        }
+
+        for (i, &value) in chunks[..count].iter().rev().enumerate() {
+            let width = if i == 0 { 0 } else { chunk_digits as usize };
+            write_radix_chunk(f, value, radix, width)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for SignedBigNumber {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl SignedBigNumber {
+    pub fn zero() -> Self {
+        Self {
+            sign: Sign::Positive,
+            magnitude: BigNumber::empty(),
+        }
+    }
+
+    /// Wraps an unsigned magnitude as a non-negative `SignedBigNumber`.
+    pub fn from_magnitude(magnitude: BigNumber) -> Self {
+        Self {
+            sign: Sign::Positive,
+            magnitude,
+        }
+    }
+
+    /// Builds a signed number from a sign and magnitude, canonicalizing a
+    /// zero magnitude to [`Sign::Positive`].
+    pub fn from_parts(sign: Sign, magnitude: BigNumber) -> Self {
+        if magnitude.is_zero() {
+            return Self::zero();
+        }
+        Self { sign, magnitude }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
     }
 }
 
 impl PartialEq for BigNumber {
     fn eq(&self, other: &Self) -> bool {
-        self.prec.iter().zip(other.prec.iter()).all(|(a, b)| a == b)
+        self.len == other.len && self.prec[..self.len] == other.prec[..self.len]
     }
 }
 
@@ -122,13 +656,18 @@ impl Eq for BigNumber {}
 
 impl Ord for BigNumber {
     fn cmp(&self, other: &Self) -> Ordering {
-        for (a, b) in self.prec.iter().zip(other.prec.iter()).rev() {
-            match a.cmp(b) {
-                Ordering::Equal => continue,
-                ord => return ord,
+        match self.len.cmp(&other.len) {
+            Ordering::Equal => {
+                for i in (0..self.len).rev() {
+                    match self.prec[i].cmp(&other.prec[i]) {
+                        Ordering::Equal => continue,
+                        ord => return ord,
+                    }
+                }
+                Ordering::Equal
             }
+            ord => ord,
         }
-        Ordering::Equal
     }
 }
 
@@ -136,8 +675,9 @@ impl AddAssign for BigNumber {
     fn add_assign(&mut self, rhs: Self) {
         let mut result = Self::new();
         let mut carry = 0;
+        let bound = self.len.max(rhs.len);
 
-        for i in 0..NUMBER_SIZE {
+        for i in 0..bound {
             let num = self.prec[i] as UpperBase + rhs.prec[i] as UpperBase + carry as UpperBase;
 
             result.prec[i] = (num % BASE) as BaseType;
@@ -149,6 +689,11 @@ impl AddAssign for BigNumber {
             }
         }
 
+        if carry != 0 && bound < NUMBER_SIZE {
+            result.prec[bound] = carry;
+        }
+
+        result.normalize(bound + 1);
         *self = result
     }
 }
@@ -165,15 +710,16 @@ impl Add for BigNumber {
 
 impl SubAssign for BigNumber {
     fn sub_assign(&mut self, rhs: Self) {
-        let mut result = Self::new();
-        let mut carry = 0;
-
         if rhs > *self {
-            *self = result;
+            *self = Self::empty();
             return;
         }
 
-        for i in 0..NUMBER_SIZE {
+        let mut result = Self::new();
+        let mut carry = 0;
+        let bound = self.len;
+
+        for i in 0..bound {
             let num = self.prec[i] as SignedUpperBase - rhs.prec[i] as SignedUpperBase
                 + carry as SignedUpperBase;
 
@@ -186,6 +732,7 @@ impl SubAssign for BigNumber {
             }
         }
 
+        result.normalize(bound);
         *self = result
     }
 }
@@ -202,27 +749,11 @@ impl Sub for BigNumber {
 
 impl MulAssign for BigNumber {
     fn mul_assign(&mut self, rhs: Self) {
-        let mut w = Self::new().prec;
-        let n = self.prec.len();
-        let t = rhs.prec.len();
-
-        for i in 0..t {
-            let mut c = 0;
-            for j in 0..n {
-                if i + j > w.len() - 1 {
-                    continue;
-                }
-
-                let uvb = w[i + j] as UpperBase
-                    + self.prec[i] as UpperBase * rhs.prec[j] as UpperBase
-                    + c as UpperBase;
-
-                w[i + j] = uvb.rem_euclid(BASE as UpperBase) as BaseType;
-                c = (uvb / BASE) as BaseType;
-            }
+        if self.len.max(rhs.len) > KARATSUBA_THRESHOLD {
+            *self = karatsuba_mul(&self.prec[..self.len], &rhs.prec[..rhs.len]);
+        } else {
+            *self = schoolbook_mul(&self.prec[..self.len], &rhs.prec[..rhs.len]);
         }
-
-        self.prec = w
     }
 }
 
@@ -238,51 +769,402 @@ impl Mul for BigNumber {
 
 impl DivAssign for BigNumber {
     fn div_assign(&mut self, divisor: Self) {
-        let mut quotient = BigNumber::new();
-        let dividend = self;
-        let mut remainder = *dividend;
+        let (quotient, _) = self.div_rem(&divisor);
+        *self = quotient;
+    }
+}
 
-        for i in (0..NUMBER_SIZE).rev() {
-            let mut shifted_divisor = divisor;
-            shifted_divisor.rotated_right(i);
+impl Div for BigNumber {
+    type Output = Self;
 
-            while remainder > shifted_divisor {
-                remainder -= shifted_divisor;
-                quotient.prec[i] += 1;
-            }
+    fn div(self, rhs: Self) -> Self::Output {
+        let mut result = self;
+        result /= rhs;
+        result
+    }
+}
+
+impl RemAssign for BigNumber {
+    fn rem_assign(&mut self, rhs: Self) {
+        let (_, remainder) = self.div_rem(&rhs);
+        *self = remainder;
+    }
+}
+
+impl Rem for BigNumber {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        let mut result = self;
+        result %= rhs;
+        result
+    }
+}
+
+impl ShlAssign<usize> for BigNumber {
+    fn shl_assign(&mut self, bits: usize) {
+        *self = self.shl_bits(bits);
+    }
+}
+
+impl Shl<usize> for BigNumber {
+    type Output = Self;
+
+    fn shl(self, bits: usize) -> Self::Output {
+        self.shl_bits(bits)
+    }
+}
+
+impl ShrAssign<usize> for BigNumber {
+    fn shr_assign(&mut self, bits: usize) {
+        *self = self.shr_bits(bits);
+    }
+}
+
+impl Shr<usize> for BigNumber {
+    type Output = Self;
+
+    fn shr(self, bits: usize) -> Self::Output {
+        self.shr_bits(bits)
+    }
+}
+
+impl BitAnd for BigNumber {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        let a = self.to_bit_words();
+        let b = rhs.to_bit_words();
+        let mut words = [0u32; NUMBER_SIZE];
+        for i in 0..NUMBER_SIZE {
+            words[i] = a[i] & b[i];
         }
+        Self::from_bit_words(&words)
+    }
+}
+
+impl BitOr for BigNumber {
+    type Output = Self;
 
-        let mut q_len = NUMBER_SIZE;
-        while q_len > 1 && quotient.prec[q_len - 1] == 0 {
-            q_len -= 1;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        let a = self.to_bit_words();
+        let b = rhs.to_bit_words();
+        let mut words = [0u32; NUMBER_SIZE];
+        for i in 0..NUMBER_SIZE {
+            words[i] = a[i] | b[i];
         }
+        Self::from_bit_words(&words)
+    }
+}
+
+impl BitXor for BigNumber {
+    type Output = Self;
 
-        *dividend = quotient
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        let a = self.to_bit_words();
+        let b = rhs.to_bit_words();
+        let mut words = [0u32; NUMBER_SIZE];
+        for i in 0..NUMBER_SIZE {
+            words[i] = a[i] ^ b[i];
+        }
+        Self::from_bit_words(&words)
     }
 }
 
-impl Div for BigNumber {
+impl Not for BigNumber {
     type Output = Self;
 
-    fn div(self, rhs: Self) -> Self::Output {
+    fn not(self) -> Self::Output {
+        let mask = (1u32 << BigNumber::BIT_CHUNK) - 1;
+        let a = self.to_bit_words();
+        let mut words = [0u32; NUMBER_SIZE];
+        for i in 0..NUMBER_SIZE {
+            words[i] = !a[i] & mask;
+        }
+        Self::from_bit_words(&words)
+    }
+}
+
+/// Error returned by [`BigNumber::from_str_radix`] and its `FromStr` impl
+/// when the radix is outside 2 to 36, the string is empty, or it contains
+/// a digit invalid for the radix.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ParseBigNumberError;
+
+impl fmt::Display for ParseBigNumberError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid digit found in string")
+    }
+}
+
+impl fmt::Display for BigNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_radix(f, 10, 9)
+    }
+}
+
+impl fmt::LowerHex for BigNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_radix(f, 16, 7)
+    }
+}
+
+impl FromStr for BigNumber {
+    type Err = ParseBigNumberError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(s, 10)
+    }
+}
+
+impl Neg for SignedBigNumber {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        let sign = match self.sign {
+            Sign::Positive => Sign::Negative,
+            Sign::Negative => Sign::Positive,
+        };
+        Self::from_parts(sign, self.magnitude)
+    }
+}
+
+impl PartialEq for SignedBigNumber {
+    fn eq(&self, other: &Self) -> bool {
+        self.sign == other.sign && self.magnitude == other.magnitude
+    }
+}
+
+impl Eq for SignedBigNumber {}
+
+impl PartialOrd for SignedBigNumber {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SignedBigNumber {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.sign, other.sign) {
+            (Sign::Negative, Sign::Positive) => Ordering::Less,
+            (Sign::Positive, Sign::Negative) => Ordering::Greater,
+            (Sign::Positive, Sign::Positive) => self.magnitude.cmp(&other.magnitude),
+            (Sign::Negative, Sign::Negative) => other.magnitude.cmp(&self.magnitude),
+        }
+    }
+}
+
+impl AddAssign for SignedBigNumber {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = if self.sign == rhs.sign {
+            Self::from_parts(self.sign, self.magnitude + rhs.magnitude)
+        } else if self.magnitude >= rhs.magnitude {
+            Self::from_parts(self.sign, self.magnitude - rhs.magnitude)
+        } else {
+            Self::from_parts(rhs.sign, rhs.magnitude - self.magnitude)
+        };
+    }
+}
+
+impl Add for SignedBigNumber {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
         let mut result = self;
-        result /= rhs;
+        result += rhs;
+        result
+    }
+}
+
+impl SubAssign for SignedBigNumber {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self += -rhs;
+    }
+}
+
+impl Sub for SignedBigNumber {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let mut result = self;
+        result -= rhs;
+        result
+    }
+}
+
+impl MulAssign for SignedBigNumber {
+    fn mul_assign(&mut self, rhs: Self) {
+        let sign = if self.sign == rhs.sign {
+            Sign::Positive
+        } else {
+            Sign::Negative
+        };
+        *self = Self::from_parts(sign, self.magnitude * rhs.magnitude);
+    }
+}
+
+impl Mul for SignedBigNumber {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut result = self;
+        result *= rhs;
         result
     }
 }
 
-// #[cfg(test)]
-// mod test {
-//     use crate::{BaseType, BigNumber};
-//
-//     #[test]
-//     fn test_1() {
-//         let mut data = BigNumber::from(u16::MAX as u32);
-//
-//         for _ in 0..10 {
-//             data *= BigNumber::from((u16::MAX) as u32);
-//         }
-//
-//         println!("number {:?}", data);
-//     }
-// }
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::{BigNumber, Sign, SignedBigNumber};
+    use bytemuck::Zeroable;
+    use core::str::FromStr;
+
+    #[test]
+    fn zeroed_is_empty() {
+        let n: BigNumber = Zeroable::zeroed();
+        assert!(n.is_zero());
+        assert_eq!(n, BigNumber::empty());
+    }
+
+    #[test]
+    fn div_rem_single_limb() {
+        let (q, r) = BigNumber::from(100).div_rem(&BigNumber::from(7));
+        assert_eq!(q, BigNumber::from(14));
+        assert_eq!(r, BigNumber::from(2));
+    }
+
+    #[test]
+    fn karatsuba_multi_level_recursion() {
+        // Operands are 70 and 75 limbs, well past `KARATSUBA_THRESHOLD`
+        // (32), so the top-level split itself recurses into Karatsuba
+        // again rather than falling back to schoolbook multiplication.
+        // Product cross-checked against Python's native multiplication.
+        let a = BigNumber::from_str(
+            "1121181770793695329703492596513851365096788326822426399987338873017255224216064034749808163750263410423024822341045181632541810071549953779914280869145633509254527551950521047878949583530340551275301734880454444859806581832280727808805271571226795110009641219728068736601396739976745407688121278731665463571828113886088259161985090996665",
+        )
+        .unwrap();
+        let b = BigNumber::from_str(
+            "15072672737926083440929256946487408604581081903027397584224494241153336867554909612150869853129143926259138376512142734307923721172152787147801667220576795643280639959932728428495956249670488687162332316442168969499919047534871036384250685699702275898746681278664962415491982864965321386429630793099973784464199351582891841179362345946763972751708311412208806133",
+        )
+        .unwrap();
+        let expected = BigNumber::from_str(
+            "16899205910901822319709842003184508277877706325228360707135306530695280404520015141108741103370308293734083904202460020294227417718576502585565947074221986610443499364289842212151776933369000893661247414492683716752697274109902415507067935637738018723363267805654551393207277552581257740269913579416206198978637121745116521184793191920631950659736644137592247310170442320914568432998677267261410395880093064720878666548309586827582278205563730562486707998065132662698407996008758687177697275707345015342314280149393971734587560011802825851678624743805914780292222775156890204288154879763920822213370694495002491126612396462287822001267008674888264640327146549938145064682780294527200895646734546445",
+        )
+        .unwrap();
+        assert_eq!(a * b, expected);
+    }
+
+    #[test]
+    fn div_rem_multi_limb_triggers_add_back() {
+        // Found by brute-forcing Algorithm D against random multi-limb
+        // inputs until one exercised the qhat-overshoot add-back correction.
+        let dividend = BigNumber::from_str("206151165539513790815902").unwrap();
+        let divisor = BigNumber::from_str("79098422125808").unwrap();
+        let (q, r) = dividend.div_rem(&divisor);
+        assert_eq!(q, BigNumber::from_str("2606261414").unwrap());
+        assert_eq!(r, BigNumber::from_str("44736546843390").unwrap());
+    }
+
+    #[test]
+    fn pow_mod_multi_limb_exponent() {
+        // base/exp/m and expected result cross-checked against Python's
+        // builtin `pow(base, exp, m)`; exp spans more than one limb, which
+        // is what exposed the old per-limb-LSB bit extraction bug.
+        let base = BigNumber::from_str("238856415091908").unwrap();
+        let exp = BigNumber::from_upper(3940988282);
+        let m = BigNumber::from_upper(771392695);
+        let result = base.pow_mod(exp, m);
+        assert_eq!(result, BigNumber::from_upper(394096554));
+    }
+
+    #[test]
+    fn bitwise_ops_cross_multi_limb_boundary() {
+        // Cross-checked against Python's native bitwise ops; `b` spans many
+        // limbs, which is what exposed the old per-limb (rather than
+        // per-bit) AND/OR/XOR/NOT.
+        let a = BigNumber::from_upper(336675749);
+        let b = BigNumber::from_str("2445343648731145162092536808261262").unwrap();
+        assert_eq!(a & b, BigNumber::from_upper(68174468));
+        assert_eq!(
+            a | b,
+            BigNumber::from_str("2445343648731145162092537076762543").unwrap()
+        );
+        assert_eq!(
+            a ^ b,
+            BigNumber::from_str("2445343648731145162092537008588075").unwrap()
+        );
+        assert_eq!(!!a, a);
+    }
+
+    #[test]
+    fn from_str_radix_rejects_out_of_range_radix() {
+        assert_eq!(
+            BigNumber::from_str_radix("10", 1),
+            Err(crate::ParseBigNumberError)
+        );
+        assert_eq!(
+            BigNumber::from_str_radix("10", 37),
+            Err(crate::ParseBigNumberError)
+        );
+    }
+
+    #[test]
+    fn display_and_hex_round_trip() {
+        let n = BigNumber::from_str("123456789012345678901234567890").unwrap();
+        assert_eq!(BigNumber::from_str(&std::format!("{n}")).unwrap(), n);
+        assert_eq!(
+            BigNumber::from_str_radix(&std::format!("{n:x}"), 16).unwrap(),
+            n
+        );
+    }
+
+    #[test]
+    fn recompute_len_after_direct_prec_write() {
+        let mut n = BigNumber::empty();
+        n.prec[2] = 5; // 5 * BASE^2 = 5 * 65535^2 = 21474181125
+        assert!(n.is_zero());
+        n.recompute_len();
+        assert!(!n.is_zero());
+        assert_eq!(n, BigNumber::from_str("21474181125").unwrap());
+    }
+
+    fn signed(sign: Sign, magnitude: u32) -> SignedBigNumber {
+        SignedBigNumber::from_parts(sign, BigNumber::from(magnitude))
+    }
+
+    #[test]
+    fn signed_add_flips_sign_on_magnitude_crossover() {
+        // (-3) + 5 == 2
+        let a = signed(Sign::Negative, 3) + signed(Sign::Positive, 5);
+        assert_eq!(a, signed(Sign::Positive, 2));
+
+        // 3 + (-5) == -2
+        let b = signed(Sign::Positive, 3) + signed(Sign::Negative, 5);
+        assert_eq!(b, signed(Sign::Negative, 2));
+    }
+
+    #[test]
+    fn signed_sub_and_mul() {
+        assert_eq!(
+            signed(Sign::Positive, 3) - signed(Sign::Positive, 5),
+            signed(Sign::Negative, 2)
+        );
+        assert_eq!(
+            signed(Sign::Negative, 3) * signed(Sign::Positive, 5),
+            signed(Sign::Negative, 15)
+        );
+        assert_eq!(
+            signed(Sign::Negative, 3) * signed(Sign::Negative, 5),
+            signed(Sign::Positive, 15)
+        );
+    }
+
+    #[test]
+    fn signed_ord_orders_negative_below_positive() {
+        let negative_large = signed(Sign::Negative, 100);
+        let positive_small = signed(Sign::Positive, 1);
+        assert!(negative_large < positive_small);
+    }
+}